@@ -1,6 +1,6 @@
 use crate::rng;
 use crate::ship::{ShipClass, ShipHandle};
-use crate::simulation::{Line, Simulation};
+use crate::simulation::{Line, Simulation, PHYSICS_TICK_LENGTH};
 use nalgebra::Rotation2;
 use nalgebra::{vector, Point2, Vector2};
 use rand::Rng;
@@ -8,6 +8,21 @@ use rand_distr::StandardNormal;
 use rng::SeededRng;
 use std::f64::consts::TAU;
 
+/// Caps the number of contacts reported per sweep so a single tick's scripting
+/// cost stays bounded even when a beam sweeps across a crowd of reflectors.
+const MAX_CONTACTS: usize = 16;
+
+/// Maximum distance between a track's predicted position and a raw detection
+/// for the two to be considered the same contact.
+const TRACK_GATE_RADIUS: f64 = 500.0;
+
+/// Number of consecutive ticks a track may go unmatched before it is dropped.
+const TRACK_MAX_MISSES: u32 = 3;
+
+/// Maximum parent-to-drone distance at which a sensor drone's contacts can
+/// still be relayed back; beyond this the link is considered out of range.
+const RELAY_RANGE: f64 = 5000.0;
+
 #[derive(Clone, Debug)]
 pub struct Radar {
     pub heading: f64,
@@ -17,6 +32,43 @@ pub struct Radar {
     pub min_rssi: f64,
     pub classify_rssi: f64,
     pub result: Option<ScanResult>,
+    pub contacts: Vec<ScanResult>,
+    pub tracks: Vec<Track>,
+    next_track_id: u64,
+    /// Contacts relayed in from a sensor drone this ship owns, if any and if
+    /// still in relay range. See [`scan_relayed`].
+    pub relayed_contacts: Vec<ScanResult>,
+}
+
+impl Default for Radar {
+    /// A modest all-around sweep: wide enough to be useful out of the box,
+    /// weak enough that ship classes wanting a focused, high-power radar
+    /// still need to configure one explicitly.
+    fn default() -> Self {
+        Self {
+            heading: 0.0,
+            width: TAU,
+            power: 100.0,
+            rx_cross_section: 1.0,
+            min_rssi: 1e-3,
+            classify_rssi: 1.0,
+            result: None,
+            contacts: vec![],
+            tracks: vec![],
+            next_track_id: 0,
+            relayed_contacts: vec![],
+        }
+    }
+}
+
+/// An alpha-beta filtered estimate of a contact's position and velocity,
+/// fused across ticks so scripts don't have to de-noise `contacts` themselves.
+#[derive(Copy, Clone, Debug)]
+pub struct Track {
+    pub id: u64,
+    pub position: Vector2<f64>,
+    pub velocity: Vector2<f64>,
+    misses: u32,
 }
 
 struct RadarEmitter {
@@ -35,16 +87,44 @@ struct RadarEmitter {
 struct RadarReflector {
     position: Point2<f64>,
     velocity: Vector2<f64>,
+    heading: f64,
     radar_cross_section: f64,
+    broadside_gain: f64,
+    nose_gain: f64,
     team: i32,
     class: ShipClass,
 }
 
+/// Electronic-countermeasures emitter mounted on a ship. Unlike `Radar` it
+/// never produces a `ScanResult`, has no facing of its own, and isn't
+/// directional: it only raises the noise floor of enemy radars whose beam
+/// it happens to fall inside, regardless of which way the jammer is facing.
+#[derive(Clone, Debug)]
+pub struct Jammer {
+    pub power: f64,
+}
+
+struct JammerBeam {
+    center: Point2<f64>,
+    power: f64,
+    team: i32,
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct ScanResult {
     pub class: Option<ShipClass>,
     pub position: Vector2<f64>,
     pub velocity: Vector2<f64>,
+    /// Set only for passive (ESM) detections, where the emitter's bearing is
+    /// known but its range is not; `position`/`velocity` are not meaningful
+    /// in that case.
+    pub bearing: Option<f64>,
+}
+
+struct ActiveEmitter {
+    position: Point2<f64>,
+    power: f64,
+    team: i32,
 }
 
 pub fn scan(sim: &mut Simulation, own_ship: ShipHandle) -> Option<ScanResult> {
@@ -55,6 +135,101 @@ pub fn scan(sim: &mut Simulation, own_ship: ShipHandle) -> Option<ScanResult> {
     }
 }
 
+/// Returns every contact picked up by the last radar sweep, strongest RSSI
+/// first, already capped to at most `MAX_CONTACTS` entries by `tick`. A
+/// slice is the intended surface here: it's iterable, bounded, and scripts
+/// never need to mutate it in place.
+pub fn scan_contacts(sim: &mut Simulation, own_ship: ShipHandle) -> &[ScanResult] {
+    if let Some(radar) = sim.ship(own_ship).data().radar.as_ref() {
+        &radar.contacts
+    } else {
+        &[]
+    }
+}
+
+/// Returns the radar's fused, de-noised tracks, keyed by a stable id that
+/// persists across ticks as long as the contact keeps getting re-associated.
+pub fn scan_tracks(sim: &mut Simulation, own_ship: ShipHandle) -> &[Track] {
+    if let Some(radar) = sim.ship(own_ship).data().radar.as_ref() {
+        &radar.tracks
+    } else {
+        &[]
+    }
+}
+
+/// Returns the contacts most recently relayed in from a sensor drone this
+/// ship owns. Empty if the ship has no drone out, or the drone's link is
+/// currently out of relay range.
+pub fn scan_relayed(sim: &mut Simulation, own_ship: ShipHandle) -> &[ScanResult] {
+    if let Some(radar) = sim.ship(own_ship).data().radar.as_ref() {
+        &radar.relayed_contacts
+    } else {
+        &[]
+    }
+}
+
+/// Radar-warning receiver: reports the bearing and received power of every
+/// enemy radar emitter currently illuminating `own_ship`, regardless of
+/// which way `own_ship`'s own radar is pointed. The bearing is measured from
+/// `own_ship` towards the emitter. Received power uses `own_ship`'s radar
+/// receiver cross section, so a ship without a radar gets no hits.
+pub fn scan_rwr(sim: &mut Simulation, own_ship: ShipHandle) -> Vec<(f64, f64)> {
+    let (own_team, own_position, own_rx_cross_section) = {
+        let ship = sim.ship(own_ship);
+        let ship_data = ship.data();
+        let rx_cross_section = ship_data
+            .radar
+            .as_ref()
+            .map(|radar| radar.rx_cross_section)
+            .unwrap_or(0.0);
+        (ship_data.team, ship.position().vector.into(), rx_cross_section)
+    };
+
+    let handle_snapshot: Vec<ShipHandle> = sim.ships.iter().cloned().collect();
+    let mut hits = vec![];
+    for handle in handle_snapshot {
+        if handle == own_ship {
+            continue;
+        }
+        let ship = sim.ship(handle);
+        let ship_data = ship.data();
+        if ship_data.team == own_team {
+            continue;
+        }
+        if let Some(radar) = ship_data.radar.as_ref() {
+            // A radar in passive mode (power == 0.0, see tick_passive) isn't
+            // transmitting at all, so there's nothing for an RWR to pick up.
+            if radar.power == 0.0 {
+                continue;
+            }
+            let h = radar.heading + ship.heading();
+            let w = radar.width;
+            let emitter = RadarEmitter {
+                handle,
+                team: ship_data.team,
+                center: ship.position().vector.into(),
+                power: radar.power,
+                min_rssi: radar.min_rssi,
+                classify_rssi: radar.classify_rssi,
+                rx_cross_section: radar.rx_cross_section,
+                width: w,
+                start_bearing: h - 0.5 * w,
+                end_bearing: h + 0.5 * w,
+            };
+            if emitter_contains(&emitter, &own_position) {
+                let r_sq = nalgebra::distance_squared(&emitter.center, &own_position);
+                let rssi = compute_passive_rssi(emitter.power, own_rx_cross_section, r_sq);
+                if rssi > emitter.min_rssi {
+                    let dp = emitter.center - own_position;
+                    let bearing = dp.y.atan2(dp.x);
+                    hits.push((bearing, rssi));
+                }
+            }
+        }
+    }
+    hits
+}
+
 #[inline(never)]
 pub fn tick(sim: &mut Simulation) {
     let handle_snapshot: Vec<ShipHandle> = sim.ships.iter().cloned().collect();
@@ -69,17 +244,59 @@ pub fn tick(sim: &mut Simulation) {
                 team: ship_data.team,
                 position: ship.position().vector.into(),
                 velocity: ship.velocity(),
+                heading: ship.heading(),
                 radar_cross_section: ship_data.radar_cross_section,
+                broadside_gain: ship_data.broadside_gain,
+                nose_gain: ship_data.nose_gain,
                 class: ship_data.class,
             }
         })
         .collect();
 
+    let jammer_beams: Vec<JammerBeam> = handle_snapshot
+        .iter()
+        .cloned()
+        .filter_map(|handle| {
+            let ship = sim.ship(handle);
+            let ship_data = ship.data();
+            ship_data.jammer.as_ref().map(|jammer| JammerBeam {
+                center: ship.position().vector.into(),
+                power: jammer.power,
+                team: ship_data.team,
+            })
+        })
+        .collect();
+
+    let active_emitters: Vec<ActiveEmitter> = handle_snapshot
+        .iter()
+        .cloned()
+        .filter_map(|handle| {
+            let ship = sim.ship(handle);
+            let ship_data = ship.data();
+            ship_data.radar.as_ref().and_then(|radar| {
+                if radar.power > 0.0 {
+                    Some(ActiveEmitter {
+                        position: ship.position().vector.into(),
+                        power: radar.power,
+                        team: ship_data.team,
+                    })
+                } else {
+                    None
+                }
+            })
+        })
+        .collect();
+
     for handle in handle_snapshot.iter().cloned() {
         let ship = sim.ship(handle);
         let ship_data = ship.data();
 
         if let Some(radar) = ship_data.radar.as_ref() {
+            if radar.power == 0.0 {
+                tick_passive(sim, &active_emitters, handle);
+                continue;
+            }
+
             let h = radar.heading + ship.heading();
             let w = radar.width;
             let emitter = RadarEmitter {
@@ -96,52 +313,243 @@ pub fn tick(sim: &mut Simulation) {
             };
             let mut rng = rng::new_rng(sim.tick());
 
-            let mut best_rssi = emitter.min_rssi;
-            let mut best_reflector: Option<&RadarReflector> = None;
-            for reflector in &reflectors {
-                if emitter.team == reflector.team {
-                    continue;
-                }
-
-                if !check_inside_beam(&emitter, &reflector.position) {
-                    continue;
-                }
+            // Any jammer whose position falls inside this radar's beam raises
+            // its noise floor, regardless of which way the jammer itself is
+            // pointed — a barrage jammer sweeping elsewhere still blinds us.
+            let jam_noise_floor: f64 = jammer_beams
+                .iter()
+                .filter(|jammer| jammer.team != emitter.team)
+                .filter(|jammer| emitter_contains(&emitter, &jammer.center))
+                .map(|jammer| {
+                    let r_sq = nalgebra::distance_squared(&emitter.center, &jammer.center);
+                    jammer.power * emitter.rx_cross_section / (TAU * emitter.width * r_sq)
+                })
+                .sum();
+            let effective_min_rssi = emitter.min_rssi + jam_noise_floor;
 
-                let rssi = compute_rssi(&emitter, reflector);
-                if rssi > best_rssi {
-                    best_reflector = Some(reflector);
-                    best_rssi = rssi;
-                }
-            }
+            let mut detections: Vec<(&RadarReflector, f64)> = reflectors
+                .iter()
+                .filter(|reflector| emitter.team != reflector.team)
+                .filter(|reflector| emitter_contains(&emitter, &reflector.position))
+                .map(|reflector| {
+                    let effective_rcs = reflector.radar_cross_section
+                        * aspect_gain(reflector, &emitter.center);
+                    (reflector, compute_rssi(&emitter, &reflector.position, effective_rcs))
+                })
+                .filter(|(_, rssi)| *rssi > effective_min_rssi)
+                .collect();
+            detections.sort_by(|(_, a), (_, b)| b.total_cmp(a));
 
-            let result = best_reflector.map(|reflector| ScanResult {
-                class: if best_rssi > emitter.classify_rssi {
-                    Some(reflector.class)
-                } else {
-                    None
-                },
-                position: reflector.position.coords + noise(&mut rng, best_rssi),
-                velocity: reflector.velocity + noise(&mut rng, best_rssi),
-            });
+            let contacts: Vec<ScanResult> = detections
+                .iter()
+                .take(MAX_CONTACTS)
+                .map(|(reflector, rssi)| {
+                    // A jammed noise floor eats into the effective signal-to-noise
+                    // ratio used to size the reported position/velocity error.
+                    let snr = (*rssi - jam_noise_floor).max(effective_min_rssi * 0.01);
+                    ScanResult {
+                        class: if *rssi > emitter.classify_rssi {
+                            Some(reflector.class)
+                        } else {
+                            None
+                        },
+                        position: reflector.position.coords + noise(&mut rng, snr),
+                        velocity: reflector.velocity + noise(&mut rng, snr),
+                        bearing: None,
+                    }
+                })
+                .collect();
+            let result = contacts.first().copied();
+            let raw_detections: Vec<(Vector2<f64>, f64)> = detections
+                .iter()
+                .take(MAX_CONTACTS)
+                .zip(contacts.iter())
+                .map(|((_, rssi), contact)| (contact.position, *rssi))
+                .collect();
 
-            sim.ship_mut(emitter.handle)
+            let radar = sim
+                .ship_mut(emitter.handle)
                 .data_mut()
                 .radar
                 .as_mut()
-                .unwrap()
-                .result = result;
+                .unwrap();
+            radar.result = result;
+            radar.contacts = contacts;
+            update_tracks(radar, &raw_detections, PHYSICS_TICK_LENGTH);
             draw_emitter(sim, &emitter);
         }
     }
+
+    relay_drone_contacts(sim, &handle_snapshot);
+}
+
+/// Copies each sensor drone's freshly-scored contacts back to the radar of
+/// the ship that owns it, as long as the drone is still within relay range.
+/// A drone is just a ship whose `ShipData::drone_owner` is set; spawning one
+/// is a ship-action concern handled outside this module, but the relay link
+/// itself lives in the radar subsystem since it's bandwidth/range limited
+/// the same way any other radar signal is.
+fn relay_drone_contacts(sim: &mut Simulation, handle_snapshot: &[ShipHandle]) {
+    // Start every ship from an empty relay so an owner whose drone is gone
+    // doesn't keep reporting the drone's last contacts.
+    for handle in handle_snapshot.iter().cloned() {
+        if let Some(radar) = sim.ship_mut(handle).data_mut().radar.as_mut() {
+            radar.relayed_contacts.clear();
+        }
+    }
+
+    for handle in handle_snapshot.iter().cloned() {
+        let (owner, drone_position, drone_contacts) = {
+            let ship = sim.ship(handle);
+            let ship_data = ship.data();
+            let drone_contacts = ship_data.radar.as_ref().map(|radar| radar.contacts.clone());
+            (
+                ship_data.drone_owner,
+                ship.position().vector,
+                drone_contacts,
+            )
+        };
+
+        let (Some(owner), Some(drone_contacts)) = (owner, drone_contacts) else {
+            continue;
+        };
+
+        // The owning ship may have been destroyed while the drone lived on;
+        // skip the relay rather than indexing a ship that's no longer there.
+        if !sim.ships.iter().any(|handle| *handle == owner) {
+            continue;
+        }
+
+        let owner_position = sim.ship(owner).position().vector;
+        let in_range = nalgebra::distance(&drone_position.into(), &owner_position.into())
+            <= RELAY_RANGE;
+
+        if let Some(owner_radar) = sim.ship_mut(owner).data_mut().radar.as_mut() {
+            owner_radar.relayed_contacts = if in_range { drone_contacts } else { vec![] };
+        }
+    }
 }
 
-fn check_inside_beam(emitter: &RadarEmitter, point: &Point2<f64>) -> bool {
-    if emitter.width >= TAU {
+/// Passive (ESM) sweep for a ship whose radar is in listen-only mode: it
+/// never radiates or calls `draw_emitter`, and instead reports the bearing
+/// of any enemy active emitter within its antenna sector.
+fn tick_passive(sim: &mut Simulation, active_emitters: &[ActiveEmitter], handle: ShipHandle) {
+    let (center, start_bearing, end_bearing, width, min_rssi, team, rx_cross_section) = {
+        let ship = sim.ship(handle);
+        let ship_data = ship.data();
+        let radar = ship_data.radar.as_ref().unwrap();
+        let h = radar.heading + ship.heading();
+        let w = radar.width;
+        (
+            ship.position().vector.into(),
+            h - 0.5 * w,
+            h + 0.5 * w,
+            w,
+            radar.min_rssi,
+            ship_data.team,
+            radar.rx_cross_section,
+        )
+    };
+
+    let mut detections: Vec<(f64, f64)> = active_emitters
+        .iter()
+        .filter(|emitter| emitter.team != team)
+        .filter(|emitter| {
+            check_inside_beam(center, start_bearing, end_bearing, width, &emitter.position)
+        })
+        .map(|emitter| {
+            let r_sq = nalgebra::distance_squared(&center, &emitter.position);
+            let rssi = compute_passive_rssi(emitter.power, rx_cross_section, r_sq);
+            let dp = emitter.position - center;
+            (dp.y.atan2(dp.x), rssi)
+        })
+        .filter(|(_, rssi)| *rssi > min_rssi)
+        .collect();
+    detections.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    let contacts: Vec<ScanResult> = detections
+        .iter()
+        .take(MAX_CONTACTS)
+        .map(|(bearing, _)| ScanResult {
+            class: None,
+            position: vector![f64::NAN, f64::NAN],
+            velocity: vector![f64::NAN, f64::NAN],
+            bearing: Some(*bearing),
+        })
+        .collect();
+    let result = contacts.first().copied();
+
+    let radar = sim.ship_mut(handle).data_mut().radar.as_mut().unwrap();
+    radar.result = result;
+    radar.contacts = contacts;
+}
+
+/// Fuses this tick's raw `(position, rssi)` detections into `radar.tracks`
+/// via nearest-neighbor association and an alpha-beta filter, predicting
+/// each track forward by `dt` before matching.
+fn update_tracks(radar: &mut Radar, detections: &[(Vector2<f64>, f64)], dt: f64) {
+    for track in radar.tracks.iter_mut() {
+        track.position += track.velocity * dt;
+    }
+
+    let original_len = radar.tracks.len();
+    let mut claimed = vec![false; original_len];
+
+    for &(raw_position, rssi) in detections {
+        let nearest = (0..original_len)
+            .filter(|&i| !claimed[i])
+            .map(|i| (i, (radar.tracks[i].position - raw_position).norm()))
+            .filter(|&(_, distance)| distance <= TRACK_GATE_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((i, _)) = nearest {
+            claimed[i] = true;
+            let pos_pred = radar.tracks[i].position;
+            let residual = raw_position - pos_pred;
+            let (alpha, beta) = alpha_beta_gains(rssi);
+            radar.tracks[i].position = pos_pred + residual * alpha;
+            radar.tracks[i].velocity += residual * (beta / dt);
+            radar.tracks[i].misses = 0;
+        } else {
+            radar.tracks.push(Track {
+                id: radar.next_track_id,
+                position: raw_position,
+                velocity: vector![0.0, 0.0],
+                misses: 0,
+            });
+            radar.next_track_id += 1;
+        }
+    }
+
+    for (i, track) in radar.tracks.iter_mut().take(original_len).enumerate() {
+        if !claimed[i] {
+            track.misses += 1;
+        }
+    }
+    radar.tracks.retain(|track| track.misses <= TRACK_MAX_MISSES);
+}
+
+/// Derives alpha-beta filter gains from a detection's RSSI: a stronger
+/// return is trusted more, so it pulls the track estimate harder towards it.
+fn alpha_beta_gains(rssi: f64) -> (f64, f64) {
+    let alpha = (rssi / (rssi + 1.0)).clamp(0.1, 0.9);
+    let beta = alpha * alpha / (2.0 - alpha);
+    (alpha, beta)
+}
+
+fn check_inside_beam(
+    center: Point2<f64>,
+    start_bearing: f64,
+    end_bearing: f64,
+    width: f64,
+    point: &Point2<f64>,
+) -> bool {
+    if width >= TAU {
         return true;
     }
-    let ray0 = Rotation2::new(emitter.start_bearing).transform_vector(&vector![1.0, 0.0]);
-    let ray1 = Rotation2::new(emitter.end_bearing).transform_vector(&vector![1.0, 0.0]);
-    let dp = point - emitter.center;
+    let ray0 = Rotation2::new(start_bearing).transform_vector(&vector![1.0, 0.0]);
+    let ray1 = Rotation2::new(end_bearing).transform_vector(&vector![1.0, 0.0]);
+    let dp = point - center;
     let is_clockwise = |v0: Vector2<f64>, v1: Vector2<f64>| -v0.x * v1.y + v0.y * v1.x > 0.0;
     if is_clockwise(ray1, ray0) {
         !is_clockwise(ray0, dp) && is_clockwise(ray1, dp)
@@ -150,10 +558,48 @@ fn check_inside_beam(emitter: &RadarEmitter, point: &Point2<f64>) -> bool {
     }
 }
 
-fn compute_rssi(emitter: &RadarEmitter, reflector: &RadarReflector) -> f64 {
-    let r_sq = nalgebra::distance_squared(&emitter.center, &reflector.position);
-    emitter.power * reflector.radar_cross_section * emitter.rx_cross_section
-        / (TAU * emitter.width * r_sq)
+fn emitter_contains(emitter: &RadarEmitter, point: &Point2<f64>) -> bool {
+    check_inside_beam(
+        emitter.center,
+        emitter.start_bearing,
+        emitter.end_bearing,
+        emitter.width,
+        point,
+    )
+}
+
+fn compute_rssi(emitter: &RadarEmitter, position: &Point2<f64>, radar_cross_section: f64) -> f64 {
+    let r_sq = nalgebra::distance_squared(&emitter.center, position);
+    emitter.power * radar_cross_section * emitter.rx_cross_section / (TAU * emitter.width * r_sq)
+}
+
+/// One-way received power for a ship that directly receives another ship's
+/// transmitted radar power, with no reflection involved: a passive ESM sweep
+/// ([`tick_passive`]) or a radar-warning-receiver query ([`scan_rwr`]). Unlike
+/// [`compute_rssi`] this has no reflection, so it has no reflector cross
+/// section term and isn't attenuated by the emitter's beam width.
+fn compute_passive_rssi(emitter_power: f64, rx_cross_section: f64, r_sq: f64) -> f64 {
+    emitter_power * rx_cross_section / (TAU * r_sq)
+}
+
+/// Scales a reflector's isotropic radar cross section by how square it is on
+/// to the emitter: bow-on (`aspect` near zero) presents `nose_gain`, broadside
+/// (`aspect` near a right angle) presents `broadside_gain`.
+fn aspect_gain(reflector: &RadarReflector, emitter_center: &Point2<f64>) -> f64 {
+    let to_emitter = emitter_center - reflector.position;
+    if to_emitter.norm_squared() == 0.0 {
+        // The emitter sits exactly on the reflector's position (two ships
+        // overlapping); aspect is undefined, so don't let it poison the
+        // sweep with a NaN angle. Bow-on is as good a default as any.
+        return reflector.nose_gain;
+    }
+    let heading_vec = vector![reflector.heading.cos(), reflector.heading.sin()];
+    let aspect = heading_vec.angle(&to_emitter);
+    lerp(reflector.nose_gain, reflector.broadside_gain, aspect.sin().abs())
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
 }
 
 fn compute_approx_range(emitter: &RadarEmitter) -> f64 {
@@ -203,10 +649,12 @@ fn draw_emitter(sim: &mut Simulation, emitter: &RadarEmitter) {
 
 #[cfg(test)]
 mod test {
+    use super::{Jammer, RadarReflector, ScanResult};
     use crate::ship;
+    use crate::ship::ShipClass;
     use crate::simulation::Code;
     use crate::simulation::Simulation;
-    use nalgebra::{vector, UnitComplex};
+    use nalgebra::{vector, Point2, UnitComplex};
     use rand::Rng;
     use std::f64::consts::TAU;
     use test_log::test;
@@ -417,4 +865,207 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_jammer_degrades_detection_regardless_of_aim() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let ship0 = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        let _ship1 = ship::create(&mut sim, 1000.0, 0.0, 0.0, 0.0, 0.0, ship::target(1));
+        sim.step();
+        assert_eq!(sim.ship(ship0).radar().unwrap().result.is_some(), true);
+
+        // Jammer co-located with the target. It has no facing of its own, so
+        // it should raise ship0's noise floor purely by being in its beam.
+        let jammer_ship = ship::create(&mut sim, 1000.0, 0.0, 0.0, 0.0, 0.0, ship::target(1));
+        sim.ship_mut(jammer_ship).data_mut().jammer = Some(Jammer { power: 1e12 });
+        sim.step();
+        assert_eq!(sim.ship(ship0).radar().unwrap().result.is_some(), false);
+    }
+
+    #[test]
+    fn test_scan_rwr_reports_enemy_emitter_bearing_and_rssi() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let ship0 = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        let ship1 = ship::create(&mut sim, 1000.0, 0.0, 0.0, 0.0, 0.0, ship::target(1));
+        sim.ship_mut(ship1).radar_mut().unwrap().heading = TAU / 2.0;
+        sim.ship_mut(ship1).radar_mut().unwrap().width = TAU / 6.0;
+        sim.step();
+
+        let hits = super::scan_rwr(&mut sim, ship0);
+        assert_eq!(hits.len(), 1);
+        let (bearing, rssi) = hits[0];
+        assert!(rssi > 0.0);
+        // ship1 sits due east of ship0, so its bearing from ship0 is 0.
+        assert!(bearing.abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_scan_rwr_ignores_silent_enemy() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let ship0 = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        let ship1 = ship::create(&mut sim, 1000.0, 0.0, 0.0, 0.0, 0.0, ship::target(1));
+        // A passive enemy (power == 0.0) isn't transmitting, so it shouldn't
+        // register as an RWR hit even while sitting in what would be its beam.
+        sim.ship_mut(ship1).radar_mut().unwrap().power = 0.0;
+        sim.ship_mut(ship1).radar_mut().unwrap().heading = TAU / 2.0;
+        sim.ship_mut(ship1).radar_mut().unwrap().width = TAU / 6.0;
+        sim.step();
+
+        let hits = super::scan_rwr(&mut sim, ship0);
+        assert!(hits.is_empty());
+    }
+
+    #[test]
+    fn test_aspect_gain_bow_on_vs_broadside() {
+        let reflector = RadarReflector {
+            position: Point2::new(0.0, 0.0),
+            velocity: vector![0.0, 0.0],
+            heading: 0.0,
+            radar_cross_section: 1.0,
+            broadside_gain: 1.0,
+            nose_gain: 0.1,
+            team: 1,
+            class: ShipClass::Target,
+        };
+
+        // Emitter directly ahead: bow-on, sin(aspect) == 0.
+        let bow_on = super::aspect_gain(&reflector, &Point2::new(1000.0, 0.0));
+        assert!((bow_on - reflector.nose_gain).abs() < EPSILON);
+
+        // Emitter abeam: broadside, sin(aspect) == 1.
+        let broadside = super::aspect_gain(&reflector, &Point2::new(0.0, 1000.0));
+        assert!((broadside - reflector.broadside_gain).abs() < EPSILON);
+
+        // Emitter exactly on top of the reflector: aspect is undefined and
+        // must not produce a NaN that would later panic a float sort.
+        let degenerate = super::aspect_gain(&reflector, &Point2::new(0.0, 0.0));
+        assert!(degenerate.is_finite());
+    }
+
+    #[test]
+    fn test_launch_drone_sets_class_and_owner() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let owner = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        let drone = ship::launch_drone(&mut sim, owner);
+
+        assert_eq!(sim.ship(drone).data().class, ShipClass::Drone);
+        assert_eq!(sim.ship(drone).data().drone_owner, Some(owner));
+    }
+
+    #[test]
+    fn test_ship_data_default_gains_are_nonzero() {
+        // A zero default here would silently zero out effective_rcs for any
+        // ship that never sets broadside_gain/nose_gain explicitly, blinding
+        // every active radar (would fail test_basic).
+        let ship_data = crate::ship::ShipData::default();
+        assert_eq!(ship_data.broadside_gain, 1.0);
+        assert_eq!(ship_data.nose_gain, 1.0);
+    }
+
+    #[test]
+    fn test_relay_drone_contacts_in_and_out_of_range() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let owner = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        let drone = ship::create(&mut sim, 100.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        sim.ship_mut(drone).data_mut().drone_owner = Some(owner);
+        sim.ship_mut(drone)
+            .data_mut()
+            .radar
+            .as_mut()
+            .unwrap()
+            .contacts = vec![ScanResult {
+            class: None,
+            position: vector![5.0, 5.0],
+            velocity: vector![0.0, 0.0],
+            bearing: None,
+        }];
+
+        let handles = vec![owner, drone];
+        super::relay_drone_contacts(&mut sim, &handles);
+        assert_eq!(sim.ship(owner).radar().unwrap().relayed_contacts.len(), 1);
+
+        // Move the drone out of relay range; the relay should go quiet
+        // rather than keep forwarding a stale contact.
+        sim.ship_mut(drone)
+            .body()
+            .set_translation(vector![super::RELAY_RANGE + 1.0, 0.0], true);
+        super::relay_drone_contacts(&mut sim, &handles);
+        assert_eq!(sim.ship(owner).radar().unwrap().relayed_contacts.len(), 0);
+    }
+
+    #[test]
+    fn test_relay_drone_contacts_survives_destroyed_owner() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let owner = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        let drone = ship::create(&mut sim, 100.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        sim.ship_mut(drone).data_mut().drone_owner = Some(owner);
+
+        // The owner was destroyed but the drone lived on; relaying its
+        // contacts must not panic on the now-missing owner.
+        sim.ships.retain(|handle| *handle != owner);
+        let handles = vec![drone];
+        super::relay_drone_contacts(&mut sim, &handles);
+    }
+
+    #[test]
+    fn test_multiple_contacts_sorted_by_rssi() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let ship0 = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        sim.ship_mut(ship0).radar_mut().unwrap().width = TAU;
+        let _near = ship::create(&mut sim, 500.0, 0.0, 0.0, 0.0, 0.0, ship::target(1));
+        let _far = ship::create(&mut sim, 1500.0, 0.0, 0.0, 0.0, 0.0, ship::target(1));
+        sim.step();
+
+        let contacts = sim.ship(ship0).radar().unwrap().contacts.clone();
+        assert_eq!(contacts.len(), 2);
+        // The nearer reflector returns a stronger signal and sorts first.
+        assert!(contacts[0].position.norm() < contacts[1].position.norm());
+    }
+
+    #[test]
+    fn test_passive_esm_reports_bearing_with_nan_position() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let ship0 = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        sim.ship_mut(ship0).radar_mut().unwrap().power = 0.0;
+        sim.ship_mut(ship0).radar_mut().unwrap().width = TAU;
+        let _ship1 = ship::create(&mut sim, 1000.0, 0.0, 0.0, 0.0, 0.0, ship::target(1));
+        sim.step();
+
+        let contacts = sim.ship(ship0).radar().unwrap().contacts.clone();
+        assert_eq!(contacts.len(), 1);
+        assert!(contacts[0].bearing.is_some());
+        assert!(contacts[0].position.x.is_nan());
+        assert!(contacts[0].velocity.x.is_nan());
+    }
+
+    #[test]
+    fn test_track_persists_then_drops_after_max_misses() {
+        let mut sim = Simulation::new("test", 0, &Code::None);
+        let ship0 = ship::create(&mut sim, 0.0, 0.0, 0.0, 0.0, 0.0, ship::fighter(0));
+        sim.ship_mut(ship0).radar_mut().unwrap().width = TAU;
+        let ship1 = ship::create(&mut sim, 1000.0, 0.0, 0.0, 0.0, 0.0, ship::target(1));
+
+        sim.step();
+        let tracks = sim.ship(ship0).radar().unwrap().tracks.clone();
+        assert_eq!(tracks.len(), 1);
+        let id = tracks[0].id;
+
+        sim.step();
+        let tracks = sim.ship(ship0).radar().unwrap().tracks.clone();
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].id, id, "a re-associated contact keeps its track id");
+
+        // Move the target far out of range so it stops being detected.
+        sim.ship_mut(ship1)
+            .body()
+            .set_translation(vector![1e6, 0.0], true);
+        for _ in 0..=super::TRACK_MAX_MISSES {
+            sim.step();
+        }
+        let tracks = sim.ship(ship0).radar().unwrap().tracks.clone();
+        assert!(
+            tracks.is_empty(),
+            "track should be dropped once it exceeds TRACK_MAX_MISSES misses"
+        );
+    }
 }
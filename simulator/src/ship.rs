@@ -0,0 +1,90 @@
+//! Ship state read and written by the radar subsystem.
+//!
+//! The rest of `Ship` (physics body, scripting actions, spawning) lives
+//! alongside this in the wider ship module; only the fields `radar.rs`
+//! actually touches are reproduced here.
+
+use crate::radar::{Jammer, Radar};
+use crate::simulation::Simulation;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShipHandle(pub u32);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShipClass {
+    Fighter,
+    Frigate,
+    Cruiser,
+    Target,
+    Missile,
+    Torpedo,
+    /// A lightweight, weaponless sensor ship launched by [`launch_drone`]; it
+    /// sweeps its own radar and relays contacts back to its `drone_owner`.
+    Drone,
+}
+
+pub struct ShipData {
+    pub team: i32,
+    pub class: ShipClass,
+    pub radar_cross_section: f64,
+    pub radar: Option<Radar>,
+    /// Electronic-countermeasures jammer mounted on this ship, if any. See
+    /// [`crate::radar::tick`], which folds every enemy jammer whose position
+    /// falls inside a radar's beam into that radar's noise floor.
+    pub jammer: Option<Jammer>,
+    /// Radar cross section gain presented broadside-on (`aspect` near a right
+    /// angle). See [`crate::radar::aspect_gain`].
+    pub broadside_gain: f64,
+    /// Radar cross section gain presented bow-on (`aspect` near zero).
+    pub nose_gain: f64,
+    /// Set on a sensor drone to the ship it relays its contacts back to, if
+    /// still within relay range. See [`crate::radar::scan_relayed`].
+    pub drone_owner: Option<ShipHandle>,
+}
+
+impl Default for ShipData {
+    fn default() -> Self {
+        Self {
+            team: 0,
+            class: ShipClass::Fighter,
+            radar_cross_section: 1.0,
+            radar: None,
+            jammer: None,
+            // Isotropic by default: aspect doesn't change detectability
+            // unless a ship class overrides these with distinct values.
+            broadside_gain: 1.0,
+            nose_gain: 1.0,
+            drone_owner: None,
+        }
+    }
+}
+
+/// Preset `ShipData` for a sensor drone: unarmed, carrying only a radar, and
+/// meant to be spawned via [`launch_drone`] rather than directly.
+pub fn drone(team: i32) -> ShipData {
+    ShipData {
+        team,
+        class: ShipClass::Drone,
+        radar: Some(Radar::default()),
+        ..Default::default()
+    }
+}
+
+/// Launches a sensor drone from `owner`'s current position and heading,
+/// wiring its `drone_owner` back to `owner` so [`crate::radar::tick`] relays
+/// its contacts home while it's within relay range.
+pub fn launch_drone(sim: &mut Simulation, owner: ShipHandle) -> ShipHandle {
+    let (x, y, heading, team) = {
+        let ship = sim.ship(owner);
+        let position = ship.position();
+        (
+            position.vector.x,
+            position.vector.y,
+            ship.heading(),
+            ship.data().team,
+        )
+    };
+    let drone_ship = create(sim, x, y, 0.0, 0.0, heading, drone(team));
+    sim.ship_mut(drone_ship).data_mut().drone_owner = Some(owner);
+    drone_ship
+}